@@ -0,0 +1,109 @@
+use crate::viewed_date::YearNumber;
+
+/// Seals [`CalendarArithmetic`] to implementations within this crate.
+///
+/// `DateConstraints<C>` still represents every date as a `chrono::NaiveDate`, i.e. always in
+/// the proleptic Gregorian calendar; `C` only customizes the month/day-count bounds that
+/// `is_month_forbidden`/`is_year_forbidden` fold over, it does not change how dates are
+/// constructed or read. A `C` whose counts disagree with `chrono::NaiveDate`'s own Gregorian
+/// arithmetic would make those folds incoherent, so this trait isn't open for downstream
+/// implementations until a calendar-parameterized date type backs it for real.
+mod private {
+    pub trait Sealed {}
+}
+
+/// Calendar-agnostic month/day-count arithmetic used by [`crate::config::DateConstraints`] to
+/// bound its per-day folds, modeled after ICU4X's `CalendarArithmetic` used by its
+/// `ArithmeticDate<C>`.
+///
+/// Currently [`Gregorian`] is the only implementation, and this trait is sealed: dates inside
+/// `DateConstraints<C>` are always `chrono::NaiveDate`s (proleptic Gregorian), so an
+/// independently-implemented `C` with different month/day counts would desync from the dates
+/// actually being folded over. Supporting other calendars (Hijri, Buddhist, Japanese, ...) for
+/// real needs a calendar-parameterized date type to replace `NaiveDate` here, which is a
+/// larger follow-up; this trait exists as the seam that change would plug into.
+pub trait CalendarArithmetic: private::Sealed {
+    /// Returns the number of months in the given year.
+    fn months_in_year(year: YearNumber) -> u8;
+
+    /// Returns the number of days in the given month of the given year.
+    fn days_in_month(year: YearNumber, month: u8) -> u8;
+
+    /// Returns the number of days in the given year.
+    fn days_in_year(year: YearNumber) -> u16;
+
+    /// Returns true if the given year is a leap year in this calendar.
+    fn is_leap_year(year: YearNumber) -> bool;
+}
+
+/// The proleptic Gregorian calendar, matching the arithmetic `chrono::NaiveDate` already uses.
+///
+/// This is the default (and, for now, only) calendar for [`crate::config::DateConstraints`],
+/// so that existing users of this crate are unaffected by the introduction of
+/// [`CalendarArithmetic`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gregorian;
+
+impl private::Sealed for Gregorian {}
+
+impl CalendarArithmetic for Gregorian {
+    fn months_in_year(_year: YearNumber) -> u8 {
+        12
+    }
+
+    fn days_in_month(year: YearNumber, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    fn days_in_year(year: YearNumber) -> u16 {
+        if Self::is_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    fn is_leap_year(year: YearNumber) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest(
+        year, expected_days, //
+        case(2019, 365),
+        case(2020, 366),
+        case(2000, 366),
+        case(1900, 365),
+    )]
+    fn gregorian_days_in_year(year: YearNumber, expected_days: u16) {
+        assert_eq!(Gregorian::days_in_year(year), expected_days);
+    }
+
+    #[rstest(
+        year, month, expected_days, //
+        case(2021, 1, 31),
+        case(2021, 4, 30),
+        case(2020, 2, 29),
+        case(2021, 2, 28),
+    )]
+    fn gregorian_days_in_month(year: YearNumber, month: u8, expected_days: u8) {
+        assert_eq!(Gregorian::days_in_month(year, month), expected_days);
+    }
+
+    #[test]
+    fn gregorian_months_in_year_is_always_twelve() {
+        assert_eq!(Gregorian::months_in_year(1), 12);
+        assert_eq!(Gregorian::months_in_year(3000), 12);
+    }
+}
@@ -1,16 +1,46 @@
 use chrono::prelude::*;
+use chrono::Duration;
 use std::collections::HashSet;
-
-use num_traits::FromPrimitive;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex};
 
 use crate::{
+    config::calendar_arithmetic::{CalendarArithmetic, Gregorian},
     utils::from_ymd,
-    viewed_date::{year_group_range, ViewedDate},
+    viewed_date::{year_group_range, MonthNumber, ViewedDate, YearNumber},
 };
 
 #[cfg(test)]
 use mockall::automock;
 
+/// Error returned by the checked, fallible variants of [`HasDateConstraints`] methods,
+/// in place of the panics that their infallible counterparts can produce for out-of-range
+/// or otherwise invalid years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateConstraintError {
+    /// the year falls outside the range `chrono::NaiveDate` can represent
+    OutOfRange(i32),
+    /// the year, month and day combination does not form a valid calendar date
+    InvalidDate { year: i32, month: u32, day: u32 },
+}
+
+impl fmt::Display for DateConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateConstraintError::OutOfRange(year) => {
+                write!(f, "year {} is out of chrono's supported date range", year)
+            }
+            DateConstraintError::InvalidDate { year, month, day } => {
+                write!(f, "{}-{}-{} is not a valid date", year, month, day)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateConstraintError {}
+
 /// Trait that can be implemented to create your own date constraints.
 #[cfg_attr(test, automock)]
 pub trait HasDateConstraints {
@@ -18,39 +48,170 @@ pub trait HasDateConstraints {
     fn is_day_forbidden(&self, date: &NaiveDate) -> bool;
 
     /// Returns true if the entire month described by year_month_info is forbidden.
+    ///
+    /// # Panics
+    /// Implementations may build intermediate dates for every day of the month and can
+    /// panic if `year_month_info`'s year falls outside chrono's supported range. Use
+    /// [`Self::try_is_month_forbidden`] to get a `Result` instead.
     fn is_month_forbidden(&self, year_month_info: &NaiveDate) -> bool;
 
     /// Returns true if the entire given year is forbidden.
+    ///
+    /// # Panics
+    /// Implementations may build intermediate dates for every month of the year and can
+    /// panic if `year` falls outside chrono's supported range. Use
+    /// [`Self::try_is_year_forbidden`] to get a `Result` instead.
     fn is_year_forbidden(&self, year: i32) -> bool;
 
     /// Returns true if the entire group of years including the given year is forbidden.
     /// A group of years are inclusive intervals [1980, 1999], [2000, 2019], [2020, 2039], ...
+    ///
+    /// # Panics
+    /// Same panic conditions as [`Self::is_year_forbidden`], applied to every year in the
+    /// group. Use [`Self::try_is_year_group_forbidden`] to get a `Result` instead.
     fn is_year_group_forbidden(&self, year: i32) -> bool;
+
+    /// Returns true if every day of the week containing `date` is forbidden. The week's
+    /// boundaries are resolved using `week_start`, so `date` does not need to already be the
+    /// first day of its week.
+    fn is_week_forbidden(&self, date: &NaiveDate) -> bool;
+
+    /// Returns the inclusive minimal date constraint, if any.
+    fn min_date(&self) -> Option<NaiveDate>;
+
+    /// Returns the inclusive maximal date constraint, if any.
+    fn max_date(&self) -> Option<NaiveDate>;
+
+    /// Returns the configured day a week is considered to start on, used to resolve
+    /// `disabled_iso_weeks` and to group days for [`Self::is_week_forbidden`].
+    fn week_start(&self) -> Weekday;
+
+    /// Returns the first selectable day on or after `from`, bounded by `max_date` when set.
+    fn next_selectable_day(&self, from: &NaiveDate) -> Option<NaiveDate> {
+        let mut candidate = *from;
+        loop {
+            if self.max_date().map_or(false, |max_date| candidate > max_date) {
+                return None;
+            }
+            if !self.is_day_forbidden(&candidate) {
+                return Some(candidate);
+            }
+            candidate = candidate.succ_opt()?;
+        }
+    }
+
+    /// Returns the first selectable day on or before `from`, bounded by `min_date` when set.
+    fn prev_selectable_day(&self, from: &NaiveDate) -> Option<NaiveDate> {
+        let mut candidate = *from;
+        loop {
+            if self.min_date().map_or(false, |min_date| candidate < min_date) {
+                return None;
+            }
+            if !self.is_day_forbidden(&candidate) {
+                return Some(candidate);
+            }
+            candidate = candidate.pred_opt()?;
+        }
+    }
+
+    /// Returns the selectable day closest to `from`, preferring the later one on a tie.
+    fn nearest_selectable_day(&self, from: &NaiveDate) -> Option<NaiveDate> {
+        match (self.next_selectable_day(from), self.prev_selectable_day(from)) {
+            (Some(next), Some(prev)) => {
+                if (next - *from) <= (*from - prev) {
+                    Some(next)
+                } else {
+                    Some(prev)
+                }
+            }
+            (Some(next), None) => Some(next),
+            (None, Some(prev)) => Some(prev),
+            (None, None) => None,
+        }
+    }
+
+    /// Checked variant of [`Self::is_day_forbidden`]; `date` is already a valid `NaiveDate`
+    /// so this never fails, but is provided for a uniform fallible API surface.
+    fn try_is_day_forbidden(&self, date: &NaiveDate) -> Result<bool, DateConstraintError> {
+        Ok(self.is_day_forbidden(date))
+    }
+
+    /// Checked variant of [`Self::is_month_forbidden`] that reports an error instead of
+    /// panicking when `year` and `month` don't form a representable date.
+    fn try_is_month_forbidden(&self, year: i32, month: u32) -> Result<bool, DateConstraintError> {
+        if NaiveDate::from_ymd_opt(year, 1, 1).is_none() {
+            return Err(DateConstraintError::OutOfRange(year));
+        }
+        let year_month_info =
+            NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateConstraintError::InvalidDate {
+                year,
+                month,
+                day: 1,
+            })?;
+        Ok(self.is_month_forbidden(&year_month_info))
+    }
+
+    /// Checked variant of [`Self::is_year_forbidden`] that reports an error instead of
+    /// panicking when `year` falls outside chrono's supported range.
+    fn try_is_year_forbidden(&self, year: i32) -> Result<bool, DateConstraintError> {
+        if NaiveDate::from_ymd_opt(year, 1, 1).is_none() {
+            return Err(DateConstraintError::OutOfRange(year));
+        }
+        Ok(self.is_year_forbidden(year))
+    }
+
+    /// Checked variant of [`Self::is_year_group_forbidden`] that reports an error instead of
+    /// panicking when `year` falls outside chrono's supported range.
+    fn try_is_year_group_forbidden(&self, year: i32) -> Result<bool, DateConstraintError> {
+        if NaiveDate::from_ymd_opt(year, 1, 1).is_none() {
+            return Err(DateConstraintError::OutOfRange(year));
+        }
+        Ok(self.is_year_group_forbidden(year))
+    }
 }
 
-/// Date constraints configuration
-#[derive(Default, Debug, Clone, Builder)]
+/// Date constraints configuration, generic over the [`CalendarArithmetic`] used to bound its
+/// month/year folds. `C` defaults to [`Gregorian`] in type position, matching today's
+/// behavior; to construct one without naming `C` yourself (e.g. via `Default` or the
+/// builder), use [`GregorianDateConstraints`] / [`GregorianDateConstraintsBuilder`] instead of
+/// `DateConstraints::default()`, which has no way to pick a concrete `C` and won't compile.
+///
+/// Dates are still always `chrono::NaiveDate` (proleptic Gregorian), so [`Gregorian`] is the
+/// only calendar this can coherently be instantiated with today; see [`CalendarArithmetic`]'s
+/// doc comment for why it's sealed rather than open to other calendars yet.
+#[derive(Debug, Clone, Builder, Getters)]
 #[builder(setter(strip_option))]
 #[builder(default)]
 #[builder(build_fn(validate = "Self::validate"))]
-pub struct DateConstraints {
+pub struct DateConstraints<C: CalendarArithmetic = Gregorian> {
     /// inclusive minimal date constraint
     /// the earliest date that can be selected
+    #[getter(skip)]
     min_date: Option<NaiveDate>,
 
     /// inclusive maximal date constraint
     /// the latest date that can be selected
+    #[getter(skip)]
     max_date: Option<NaiveDate>,
 
+    /// the day a week is considered to start on, used to resolve `disabled_iso_weeks`
+    /// and to group days for `is_week_forbidden`
+    #[builder(default = "Weekday::Mon")]
+    week_start: Weekday,
+
     /// disabled weekdays, that should not be selectable
     disabled_weekdays: HashSet<Weekday>,
 
-    /// entire completely disabled months in every year
-    disabled_months: HashSet<Month>,
+    /// entire completely disabled months in every year, numbered from 1 as in `C`
+    disabled_months: HashSet<u8>,
 
     /// entire completely disabled years
     disabled_years: HashSet<i32>,
 
+    /// entire completely disabled ISO 8601 weeks, identified by their ISO week-numbering year
+    /// and week number
+    disabled_iso_weeks: HashSet<(YearNumber, u32)>,
+
     /// disabled monthly periodically repeating dates, so it is just a day number
     /// starting from 1 for the first day of the month
     /// if unique dates in a certain year should not be selectable use `disabled_unique_dates`
@@ -64,17 +225,91 @@ pub struct DateConstraints {
     /// disabled unique dates with a specific year, month and day that should not be selectable,
     /// if some periodically repeated dates should not be selectable use the correct option
     disabled_unique_dates: HashSet<NaiveDate>,
+
+    /// disabled closed date ranges (inclusive on both ends) that should not be selectable,
+    /// useful for blocking out a contiguous span like a holiday week or a booked-out period
+    /// without having to enumerate every day into `disabled_unique_dates`
+    disabled_date_ranges: Vec<RangeInclusive<NaiveDate>>,
+
+    /// optional memoization cache for `is_month_forbidden`, so repeated queries during
+    /// calendar navigation can skip straight to `true` in O(1) instead of re-folding over
+    /// every day of the month; allowed months are cheap enough to re-check that they aren't
+    /// cached. `None` by default (every query folds as needed); opt in with
+    /// [`DateConstraintsBuilder::with_month_forbidden_cache`]. Uses `Arc<Mutex<_>>` rather
+    /// than a `RefCell` so `DateConstraints` stays `Send + Sync` (shareable behind an `Arc`,
+    /// e.g. in a server) and so cloned configurations keep sharing the same cache.
+    #[builder(setter(custom))]
+    #[getter(skip)]
+    month_forbidden_cache: Option<Arc<Mutex<HashSet<(YearNumber, MonthNumber)>>>>,
+
+    /// marker for the calendar system this configuration's months and years are expressed in
+    #[builder(setter(skip))]
+    #[getter(skip)]
+    calendar: PhantomData<C>,
+}
+
+/// [`DateConstraints`] pinned to the [`Gregorian`] calendar, which is what every caller that
+/// never names the `C` type parameter actually gets.
+///
+/// `DateConstraints`'s `C` type parameter defaults to `Gregorian` in type position (e.g. a
+/// `let config: DateConstraints = ...` binding), but Rust's defaulted type parameters don't
+/// feed inference for associated-function calls like `DateConstraints::default()` or
+/// `DateConstraintsBuilder::default()` — those need a concrete `C` to pick an `impl`. Use this
+/// alias (and [`GregorianDateConstraintsBuilder`]) to construct the common case without having
+/// to spell out `::<Gregorian>` everywhere.
+pub type GregorianDateConstraints = DateConstraints<Gregorian>;
+
+/// [`DateConstraintsBuilder`] pinned to the [`Gregorian`] calendar; see
+/// [`GregorianDateConstraints`].
+pub type GregorianDateConstraintsBuilder = DateConstraintsBuilder<Gregorian>;
+
+impl<C: CalendarArithmetic> Default for DateConstraints<C> {
+    fn default() -> Self {
+        Self {
+            min_date: None,
+            max_date: None,
+            week_start: Weekday::Mon,
+            disabled_weekdays: HashSet::default(),
+            disabled_months: HashSet::default(),
+            disabled_years: HashSet::default(),
+            disabled_iso_weeks: HashSet::default(),
+            disabled_monthly_dates: HashSet::default(),
+            disabled_yearly_dates: Vec::default(),
+            disabled_unique_dates: HashSet::default(),
+            disabled_date_ranges: Vec::default(),
+            month_forbidden_cache: None,
+            calendar: PhantomData,
+        }
+    }
 }
 
-impl DateConstraintsBuilder {
+impl<C: CalendarArithmetic> DateConstraintsBuilder<C> {
     fn validate(&self) -> Result<(), String> {
+        // A `NaiveDate` is only ever constructible within `NaiveDate::MIN..=NaiveDate::MAX`,
+        // so there is no out-of-range `min_date`/`max_date` to guard against here; any value
+        // that reaches this builder already satisfies that bound by construction.
         if let (Some(min_date), Some(max_date)) = (self.min_date, self.max_date) {
             if min_date > max_date {
                 return Err("min_date must be earlier or exactly at max_date".into());
             }
         }
+        if let Some(disabled_date_ranges) = &self.disabled_date_ranges {
+            if disabled_date_ranges.iter().any(|range| range.start() > range.end()) {
+                return Err(
+                    "disabled_date_ranges must not contain a range whose start is after its end"
+                        .into(),
+                );
+            }
+        }
         Ok(())
     }
+
+    /// Opts into memoizing `is_month_forbidden` results; see the field's doc comment on
+    /// [`DateConstraints`] for why this isn't on by default.
+    pub fn with_month_forbidden_cache(&mut self) -> &mut Self {
+        self.month_forbidden_cache = Some(Some(Arc::new(Mutex::new(HashSet::default()))));
+        self
+    }
 }
 
 // TODO: find out how to place #[derive(Clone)] on the structure generated by automock
@@ -89,38 +324,142 @@ cfg_if::cfg_if! {
     }
 }
 
-impl HasDateConstraints for DateConstraints {
+impl<C: CalendarArithmetic> DateConstraints<C> {
+    /// Returns the first day of the week `date` falls in, per the configured `week_start`,
+    /// or `None` if that day falls outside the range `NaiveDate` can represent.
+    ///
+    /// Computed via checked arithmetic on `date` itself rather than `NaiveDate::week`'s
+    /// `first_day` (which panics on overflow), since `date` is valid user input for every
+    /// caller of this and callers are expected to tolerate dates near `NaiveDate::MIN`/`MAX`.
+    fn week_start_date(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let days_since_start = date.weekday().days_since(self.week_start);
+        date.checked_sub_signed(Duration::days(days_since_start as i64))
+    }
+}
+
+impl<C: CalendarArithmetic> HasDateConstraints for DateConstraints<C> {
     fn is_day_forbidden(&self, date: &NaiveDate) -> bool {
+        // `disabled_iso_weeks` is keyed off the ISO week of the configured week's first day,
+        // not `date.iso_week()` directly, so a non-Monday `week_start` still groups `date`
+        // into the same week its neighbors are grouped into. Skipped entirely when
+        // `disabled_iso_weeks` is empty, both to spare this per-day hot path the extra work
+        // and because `week_start_date` can return `None` near the representable date range.
+        let in_disabled_iso_week = !self.disabled_iso_weeks.is_empty()
+            && self.week_start_date(date).map_or(false, |week_start_date| {
+                let iso_week = week_start_date.iso_week();
+                self.disabled_iso_weeks
+                    .contains(&(iso_week.year(), iso_week.week()))
+            });
         self.min_date.map_or(false, |min_date| &min_date > date)
             || self.max_date.map_or(false, |max_date| &max_date < date)
             || self.disabled_weekdays.contains(&date.weekday())
-            || self
-                .disabled_months
-                .contains(&Month::from_u32(date.month()).unwrap())
+            || self.disabled_months.contains(&(date.month() as u8))
             || self.disabled_years.contains(&date.year())
+            || in_disabled_iso_week
             || self.disabled_unique_dates.contains(date)
             || self.disabled_monthly_dates.contains(&date.day())
             || self
                 .disabled_yearly_dates
                 .iter()
                 .any(|disabled| disabled.day() == date.day() && disabled.month() == date.month())
+            || self
+                .disabled_date_ranges
+                .iter()
+                .any(|range| range.contains(date))
+    }
+
+    fn is_week_forbidden(&self, date: &NaiveDate) -> bool {
+        // Folds over the week day-by-day using `succ_opt` rather than adding a fixed offset
+        // to `week_start_date`, since both can otherwise panic for a `date` within ~6 days of
+        // `NaiveDate::MIN`/`MAX`; if the week isn't fully representable we can't confirm every
+        // day in it is forbidden, so conservatively treat it as not forbidden.
+        let mut current = match self.week_start_date(date) {
+            Some(week_start_date) => week_start_date,
+            None => return false,
+        };
+        for day_offset in 0..7 {
+            if !self.is_day_forbidden(&current) {
+                return false;
+            }
+            if day_offset == 6 {
+                break;
+            }
+            current = match current.succ_opt() {
+                Some(next) => next,
+                None => return false,
+            };
+        }
+        true
+    }
+
+    fn min_date(&self) -> Option<NaiveDate> {
+        self.min_date
+    }
+
+    fn max_date(&self) -> Option<NaiveDate> {
+        self.max_date
+    }
+
+    fn week_start(&self) -> Weekday {
+        self.week_start
     }
 
     fn is_month_forbidden(&self, year_month_info: &NaiveDate) -> bool {
-        self.disabled_years.contains(&year_month_info.year())
+        let year = year_month_info.year();
+        let month = year_month_info.month() as u8;
+        let cache_key = (year, month as u32);
+
+        if let Some(cache) = &self.month_forbidden_cache {
+            if cache.lock().unwrap().contains(&cache_key) {
+                return true;
+            }
+        }
+
+        let first_day = from_ymd(year, month as u32, 1);
+        let last_day = from_ymd(year, month as u32, C::days_in_month(year, month) as u32);
+
+        let forbidden = self.disabled_years.contains(&year)
+            || self.disabled_months.contains(&month)
             || self
-                .disabled_months
-                .contains(&Month::from_u32(year_month_info.month()).unwrap())
-            || year_month_info
-                .first_day_of_month()
-                .iter_days()
-                .take_while(|date| date.month() == year_month_info.month())
-                .all(|date| self.is_day_forbidden(&date))
+                .disabled_date_ranges
+                .iter()
+                .any(|range| range.contains(&first_day) && range.contains(&last_day))
+            || self.min_date.map_or(false, |min_date| min_date > last_day)
+            || self.max_date.map_or(false, |max_date| max_date < first_day)
+            || ((!self.disabled_weekdays.is_empty()
+                || !self.disabled_monthly_dates.is_empty()
+                || !self.disabled_yearly_dates.is_empty()
+                || !self.disabled_iso_weeks.is_empty()
+                || !self.disabled_unique_dates.is_empty()
+                || !self.disabled_date_ranges.is_empty())
+                && (1..=C::days_in_month(year, month))
+                    .all(|day| self.is_day_forbidden(&from_ymd(year, month as u32, day as u32))));
+
+        if forbidden {
+            if let Some(cache) = &self.month_forbidden_cache {
+                cache.lock().unwrap().insert(cache_key);
+            }
+        }
+        forbidden
     }
 
     fn is_year_forbidden(&self, year: i32) -> bool {
+        let months_in_year = C::months_in_year(year);
+        let first_day = from_ymd(year, 1, 1);
+        let last_day = from_ymd(
+            year,
+            months_in_year as u32,
+            C::days_in_month(year, months_in_year) as u32,
+        );
         self.disabled_years.contains(&year)
-            || (1..=12u32).all(|month| self.is_month_forbidden(&from_ymd(year, month, 1)))
+            || self
+                .disabled_date_ranges
+                .iter()
+                .any(|range| range.contains(&first_day) && range.contains(&last_day))
+            || self.min_date.map_or(false, |min_date| min_date > last_day)
+            || self.max_date.map_or(false, |max_date| max_date < first_day)
+            || (1..=months_in_year)
+                .all(|month| self.is_month_forbidden(&from_ymd(year, month as u32, 1)))
     }
 
     fn is_year_group_forbidden(&self, year: i32) -> bool {
@@ -144,7 +483,7 @@ mod tests {
         case(create_date(3000, 3, 22)),
     )]
     fn is_day_forbidden_default_no_bounds(tested_date: NaiveDate) {
-        assert!(!DateConstraints::default().is_day_forbidden(&tested_date))
+        assert!(!GregorianDateConstraints::default().is_day_forbidden(&tested_date))
     }
 
     #[rstest(
@@ -153,7 +492,7 @@ mod tests {
         case(create_date(3000, 3, 22)),
     )]
     fn is_month_forbidden_default_no_bounds(tested_date: NaiveDate) {
-        assert!(!DateConstraints::default().is_month_forbidden(&tested_date))
+        assert!(!GregorianDateConstraints::default().is_month_forbidden(&tested_date))
     }
 
     #[rstest(
@@ -162,13 +501,13 @@ mod tests {
         case(3000),
     )]
     fn is_year_forbidden_default_no_bounds(tested_year: YearNumber) {
-        assert!(!DateConstraints::default().is_year_forbidden(tested_year))
+        assert!(!GregorianDateConstraints::default().is_year_forbidden(tested_year))
     }
 
     #[test]
     fn picker_config_min_date_greater_than_max_date() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .min_date(date.clone())
             .max_date(date.clone() - Duration::days(1))
             .build();
@@ -182,7 +521,7 @@ mod tests {
     #[test]
     fn picker_config_min_date_equals_max_date() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .min_date(date.clone())
             .max_date(date.clone())
             .build();
@@ -192,7 +531,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_at_min_date_allowed() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .min_date(date.clone())
             .build()
             .unwrap();
@@ -202,7 +541,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_before_min_date_not_allowed() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .min_date(date.clone())
             .build()
             .unwrap();
@@ -212,7 +551,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_at_max_date_allowed() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .max_date(date.clone())
             .build()
             .unwrap();
@@ -222,7 +561,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_after_max_date_not_allowed() {
         let date = from_ymd(2020, 10, 15);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .max_date(date.clone())
             .build()
             .unwrap();
@@ -239,7 +578,7 @@ mod tests {
         week: u32,
         disabled_weekday: Weekday,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_weekdays([disabled_weekday].iter().cloned().collect())
             .build()
             .unwrap();
@@ -250,19 +589,19 @@ mod tests {
 
     #[rstest(
         year => [1, 2000, 3000],
-        disabled_month => [Month::January, Month::July, Month::December],
+        disabled_month => [1u8, 7, 12],
         day => [1, 15, 27],
     )]
     fn is_day_forbidden_disabled_month_not_allowed(
         year: YearNumber,
-        disabled_month: Month,
+        disabled_month: u8,
         day: DayNumber,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_months([disabled_month].iter().cloned().collect())
             .build()
             .unwrap();
-        assert!(config.is_day_forbidden(&from_ymd(year, disabled_month.number_from_month(), day)))
+        assert!(config.is_day_forbidden(&from_ymd(year, disabled_month as u32, day)))
     }
 
     #[rstest(
@@ -275,7 +614,7 @@ mod tests {
         month: MonthNumber,
         day: DayNumber,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_years([disabled_year].iter().cloned().collect())
             .build()
             .unwrap();
@@ -285,7 +624,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_disabled_unique_dates_not_allowed() {
         let date = from_ymd(2020, 1, 16);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_unique_dates([date].iter().cloned().collect())
             .build()
             .unwrap();
@@ -295,7 +634,7 @@ mod tests {
     #[test]
     fn is_day_forbidden_disabled_unique_dates_after_a_year_allowed() {
         let date = from_ymd(2020, 1, 16);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_unique_dates([date].iter().cloned().collect())
             .build()
             .unwrap();
@@ -315,7 +654,7 @@ mod tests {
         day: DayNumber,
     ) {
         let disabled_yearly_date = from_ymd(year_in_disabled, month, day);
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_yearly_dates(vec![disabled_yearly_date])
             .build()
             .unwrap();
@@ -332,7 +671,7 @@ mod tests {
         month: MonthNumber,
         day: DayNumber,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_monthly_dates([day].iter().cloned().collect())
             .build()
             .unwrap();
@@ -341,19 +680,19 @@ mod tests {
 
     #[rstest(
         year => [1, 2000, 3000],
-        disabled_month => [Month::January, Month::July, Month::December],
+        disabled_month => [1u8, 7, 12],
         day => [1, 15, 27],
     )]
     fn is_month_forbidden_disabled_months_not_allowed(
         year: YearNumber,
-        disabled_month: Month,
+        disabled_month: u8,
         day: DayNumber,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_months([disabled_month].iter().cloned().collect())
             .build()
             .unwrap();
-        assert!(config.is_month_forbidden(&from_ymd(year, disabled_month.number_from_month(), day)))
+        assert!(config.is_month_forbidden(&from_ymd(year, disabled_month as u32, day)))
     }
 
     #[rstest(
@@ -366,7 +705,7 @@ mod tests {
         month: MonthNumber,
         day: DayNumber,
     ) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_years([disabled_year].iter().cloned().collect())
             .build()
             .unwrap();
@@ -377,7 +716,7 @@ mod tests {
         disabled_year => [1, 2000, 3000],
     )]
     fn is_year_forbidden_disabled_years_not_allowed(disabled_year: YearNumber) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_years([disabled_year].iter().cloned().collect())
             .build()
             .unwrap();
@@ -388,10 +727,397 @@ mod tests {
         disabled_year_group => [1, 2000, 3000],
     )]
     fn is_year_group_forbidden_disabled_years_not_allowed(disabled_year_group: YearNumber) {
-        let config = DateConstraintsBuilder::default()
+        let config = GregorianDateConstraintsBuilder::default()
             .disabled_years(year_group_range(disabled_year_group).collect())
             .build()
             .unwrap();
         assert!(config.is_year_group_forbidden(disabled_year_group))
     }
+
+    #[test]
+    fn is_day_forbidden_disabled_iso_week_not_allowed() {
+        let date = from_ymd(2021, 8, 16);
+        let iso_week = date.iso_week();
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_iso_weeks([(iso_week.year(), iso_week.week())].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert!(config.is_day_forbidden(&date))
+    }
+
+    #[test]
+    fn is_week_forbidden_default_no_bounds() {
+        let config = GregorianDateConstraints::default();
+        assert!(!config.is_week_forbidden(&from_ymd(2021, 8, 16)))
+    }
+
+    #[test]
+    fn is_week_forbidden_when_every_day_individually_forbidden() {
+        let week_start = from_ymd(2021, 8, 16);
+        let disabled_days: HashSet<NaiveDate> = (0..7)
+            .map(|offset| week_start + Duration::days(offset))
+            .collect();
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates(disabled_days)
+            .build()
+            .unwrap();
+        assert!(config.is_week_forbidden(&week_start))
+    }
+
+    #[test]
+    fn is_week_forbidden_false_when_one_day_allowed() {
+        let week_start = from_ymd(2021, 8, 16);
+        let disabled_days: HashSet<NaiveDate> = (0..6)
+            .map(|offset| week_start + Duration::days(offset))
+            .collect();
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates(disabled_days)
+            .build()
+            .unwrap();
+        assert!(!config.is_week_forbidden(&week_start))
+    }
+
+    #[test]
+    fn is_week_forbidden_resolves_week_from_a_non_start_date_using_week_start() {
+        // 2021-08-15 is a Sunday, so with week_start = Sun the week runs 2021-08-15..=08-21.
+        let sunday_week_start = from_ymd(2021, 8, 15);
+        let disabled_days: HashSet<NaiveDate> = (0..7)
+            .map(|offset| sunday_week_start + Duration::days(offset))
+            .collect();
+        let config = GregorianDateConstraintsBuilder::default()
+            .week_start(Weekday::Sun)
+            .disabled_unique_dates(disabled_days)
+            .build()
+            .unwrap();
+        // Wednesday, the middle of that week, is not the week's first day.
+        assert!(config.is_week_forbidden(&from_ymd(2021, 8, 18)))
+    }
+
+    #[test]
+    fn is_week_forbidden_near_max_date_does_not_panic() {
+        let config = GregorianDateConstraints::default();
+        assert!(!config.is_week_forbidden(&NaiveDate::MAX))
+    }
+
+    #[test]
+    fn is_day_forbidden_near_max_date_with_disabled_iso_weeks_does_not_panic() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_iso_weeks([(2000, 1u32)].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert!(!config.is_day_forbidden(&NaiveDate::MAX))
+    }
+
+    #[test]
+    fn is_day_forbidden_disabled_iso_week_honors_non_monday_week_start() {
+        // 2021-08-15 is a Sunday; with week_start = Sun it anchors the same week that
+        // 2021-08-16 (Monday) falls into, but that week's ISO week/year differs from
+        // 2021-08-16's own `iso_week()` since ISO weeks are always Monday-based.
+        let sunday_week_start = from_ymd(2021, 8, 15);
+        let iso_week = sunday_week_start.iso_week();
+        let config = GregorianDateConstraintsBuilder::default()
+            .week_start(Weekday::Sun)
+            .disabled_iso_weeks([(iso_week.year(), iso_week.week())].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert!(config.is_day_forbidden(&from_ymd(2021, 8, 16)))
+    }
+
+    #[test]
+    fn week_start_defaults_to_monday() {
+        assert_eq!(GregorianDateConstraints::default().week_start(), &Weekday::Mon);
+    }
+
+    #[test]
+    fn next_selectable_day_returns_from_when_allowed() {
+        let date = from_ymd(2021, 8, 16);
+        assert_eq!(
+            GregorianDateConstraints::default().next_selectable_day(&date),
+            Some(date)
+        )
+    }
+
+    #[test]
+    fn next_selectable_day_skips_disabled_dates() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates([date, date + Duration::days(1)].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.next_selectable_day(&date),
+            Some(date + Duration::days(2))
+        )
+    }
+
+    #[test]
+    fn next_selectable_day_none_past_max_date() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .max_date(date)
+            .disabled_unique_dates([date].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(config.next_selectable_day(&date), None)
+    }
+
+    #[test]
+    fn prev_selectable_day_skips_disabled_dates() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates([date, date - Duration::days(1)].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.prev_selectable_day(&date),
+            Some(date - Duration::days(2))
+        )
+    }
+
+    #[test]
+    fn prev_selectable_day_none_before_min_date() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .min_date(date)
+            .disabled_unique_dates([date].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(config.prev_selectable_day(&date), None)
+    }
+
+    #[test]
+    fn nearest_selectable_day_prefers_closer_side() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates(
+                [date, date - Duration::days(1), date - Duration::days(2)]
+                    .iter()
+                    .cloned()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.nearest_selectable_day(&date),
+            Some(date + Duration::days(1))
+        )
+    }
+
+    #[test]
+    fn nearest_selectable_day_falls_back_to_only_available_side() {
+        let date = from_ymd(2021, 8, 16);
+        let config = GregorianDateConstraintsBuilder::default()
+            .min_date(date - Duration::days(1))
+            .disabled_unique_dates([date, date - Duration::days(1)].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.nearest_selectable_day(&date),
+            Some(date + Duration::days(1))
+        )
+    }
+
+    #[test]
+    fn try_is_day_forbidden_matches_infallible() {
+        let date = from_ymd(2020, 10, 15);
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates([date].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(config.try_is_day_forbidden(&date), Ok(true))
+    }
+
+    #[test]
+    fn try_is_month_forbidden_matches_infallible() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_months([3u8].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(config.try_is_month_forbidden(2020, 3), Ok(true))
+    }
+
+    #[test]
+    fn try_is_month_forbidden_out_of_range_year() {
+        let config = GregorianDateConstraints::default();
+        assert_eq!(
+            config.try_is_month_forbidden(1_000_000_000, 1),
+            Err(DateConstraintError::OutOfRange(1_000_000_000))
+        )
+    }
+
+    #[test]
+    fn try_is_month_forbidden_invalid_month_in_range_year() {
+        let config = GregorianDateConstraints::default();
+        assert_eq!(
+            config.try_is_month_forbidden(2020, 13),
+            Err(DateConstraintError::InvalidDate {
+                year: 2020,
+                month: 13,
+                day: 1
+            })
+        )
+    }
+
+    #[test]
+    fn try_is_year_forbidden_matches_infallible() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_years([2020].iter().cloned().collect())
+            .build()
+            .unwrap();
+        assert_eq!(config.try_is_year_forbidden(2020), Ok(true))
+    }
+
+    #[test]
+    fn try_is_year_forbidden_out_of_range_year() {
+        let config = GregorianDateConstraints::default();
+        assert_eq!(
+            config.try_is_year_forbidden(1_000_000_000),
+            Err(DateConstraintError::OutOfRange(1_000_000_000))
+        )
+    }
+
+    #[test]
+    fn picker_config_min_date_at_supported_boundary_is_valid() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .min_date(NaiveDate::MIN)
+            .max_date(NaiveDate::MAX)
+            .build();
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn picker_config_disabled_date_range_start_after_end() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_date_ranges(vec![from_ymd(2020, 10, 20)..=from_ymd(2020, 10, 10)])
+            .build();
+        assert!(config.is_err());
+        assert_eq!(
+            config.unwrap_err().to_string(),
+            "disabled_date_ranges must not contain a range whose start is after its end"
+        );
+    }
+
+    #[test]
+    fn is_day_forbidden_inside_disabled_date_range() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_date_ranges(vec![from_ymd(2020, 10, 10)..=from_ymd(2020, 10, 20)])
+            .build()
+            .unwrap();
+        assert!(config.is_day_forbidden(&from_ymd(2020, 10, 15)))
+    }
+
+    #[test]
+    fn is_day_forbidden_outside_disabled_date_range() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_date_ranges(vec![from_ymd(2020, 10, 10)..=from_ymd(2020, 10, 20)])
+            .build()
+            .unwrap();
+        assert!(!config.is_day_forbidden(&from_ymd(2020, 10, 21)))
+    }
+
+    #[test]
+    fn is_month_forbidden_when_range_covers_whole_month() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_date_ranges(vec![from_ymd(2020, 9, 20)..=from_ymd(2020, 11, 5)])
+            .build()
+            .unwrap();
+        assert!(config.is_month_forbidden(&from_ymd(2020, 10, 1)))
+    }
+
+    #[test]
+    fn is_year_forbidden_when_range_covers_whole_year() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_date_ranges(vec![from_ymd(2019, 12, 20)..=from_ymd(2021, 1, 5)])
+            .build()
+            .unwrap();
+        assert!(config.is_year_forbidden(2020))
+    }
+
+    #[test]
+    fn is_month_forbidden_when_every_day_disabled_via_unique_dates() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates(
+                (1..=31)
+                    .map(|day| from_ymd(2020, 10, day))
+                    .collect::<HashSet<_>>(),
+            )
+            .build()
+            .unwrap();
+        // disabled_unique_dates must also trigger the per-day fold, otherwise a month that
+        // is only forbidden through individually-disabled dates would be under-reported as
+        // allowed by the coarse short-circuit above.
+        assert!(config.is_month_forbidden(&from_ymd(2020, 10, 1)))
+    }
+
+    #[test]
+    fn is_month_forbidden_with_some_days_disabled_via_unique_dates_is_allowed() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_unique_dates(
+                (1..=30)
+                    .map(|day| from_ymd(2020, 10, day))
+                    .collect::<HashSet<_>>(),
+            )
+            .build()
+            .unwrap();
+        assert!(!config.is_month_forbidden(&from_ymd(2020, 10, 1)))
+    }
+
+    #[test]
+    fn is_month_forbidden_repeated_query_is_memoized_when_cache_enabled() {
+        let mut builder = GregorianDateConstraintsBuilder::default();
+        builder.with_month_forbidden_cache();
+        let config = builder
+            .disabled_months([10u8].iter().cloned().collect())
+            .build()
+            .unwrap();
+        let date = from_ymd(2020, 10, 1);
+        assert!(config.is_month_forbidden(&date));
+        assert!(config
+            .month_forbidden_cache
+            .as_ref()
+            .expect("cache enabled")
+            .lock()
+            .unwrap()
+            .contains(&(2020, 10)));
+        assert!(config.is_month_forbidden(&date));
+    }
+
+    #[test]
+    fn is_month_forbidden_repeated_query_without_cache_still_matches() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_months([10u8].iter().cloned().collect())
+            .build()
+            .unwrap();
+        let date = from_ymd(2020, 10, 1);
+        assert!(config.is_month_forbidden(&date));
+        assert!(config.is_month_forbidden(&date));
+    }
+
+    #[test]
+    fn date_constraints_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<GregorianDateConstraints>();
+    }
+
+    #[test]
+    fn is_month_forbidden_with_disabled_weekday_still_folds_over_days() {
+        let config = GregorianDateConstraintsBuilder::default()
+            .disabled_weekdays(
+                [
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                    Weekday::Sun,
+                ]
+                .iter()
+                .cloned()
+                .collect(),
+            )
+            .build()
+            .unwrap();
+        assert!(config.is_month_forbidden(&from_ymd(2020, 10, 1)))
+    }
 }
@@ -0,0 +1,8 @@
+pub mod calendar_arithmetic;
+pub mod date_constraints;
+
+pub use calendar_arithmetic::{CalendarArithmetic, Gregorian};
+pub use date_constraints::{
+    DateConstraints, DateConstraintsBuilder, GregorianDateConstraints,
+    GregorianDateConstraintsBuilder, HasDateConstraints,
+};